@@ -0,0 +1,69 @@
+// A `String` is really just a three-field stack record: a pointer to the
+// heap buffer, a length, and a capacity. `let s2 = s1` copies that triple
+// and moves ownership of the buffer; `s1.clone()` allocates a fresh buffer.
+// `describe` exposes the triple directly, so the tests below can check the
+// copy/move/clone story against real pointer values.
+
+/// Returns `(pointer, length, capacity)` for a `String`, as described in
+/// the comments in `strings()`.
+pub fn describe(s: &String) -> (usize, usize, usize) {
+    (s.as_ptr() as usize, s.len(), s.capacity())
+}
+
+/// Appends to a `String` one chunk at a time, printing the capacity each
+/// time it changes so the reallocation points become visible.
+pub fn demo_growth() {
+    let mut s = String::new();
+    let mut last_capacity = s.capacity();
+    println!("capacity {last_capacity} (len {})", s.len());
+
+    for _ in 0..5 {
+        s.push_str("0123456789");
+        if s.capacity() != last_capacity {
+            last_capacity = s.capacity();
+            println!("reallocated: capacity {last_capacity} (len {})", s.len());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clone_allocates_a_new_buffer() {
+        let s1 = String::from("hello");
+        let s2 = s1.clone();
+
+        let (ptr1, ..) = describe(&s1);
+        let (ptr2, ..) = describe(&s2);
+        assert_ne!(ptr1, ptr2);
+    }
+
+    #[test]
+    fn move_preserves_the_buffer_address() {
+        let s1 = String::from("hello");
+        let (ptr1, len1, cap1) = describe(&s1);
+
+        let s2 = s1; // move: the stack triple is copied, the heap buffer is not.
+        let (ptr2, len2, cap2) = describe(&s2);
+
+        assert_eq!(ptr1, ptr2);
+        assert_eq!(len1, len2);
+        assert_eq!(cap1, cap2);
+    }
+
+    #[test]
+    fn pushing_past_capacity_reallocates() {
+        let mut s = String::with_capacity(4);
+        let (_, _, cap_before) = describe(&s);
+
+        s.push_str("this string is longer than four bytes");
+        let (_, _, cap_after) = describe(&s);
+
+        // Only capacity growth is guaranteed here; whether the allocator
+        // can grow the buffer in place (vs. moving it) isn't part of
+        // String's contract, so don't assert on the pointer.
+        assert!(cap_after > cap_before);
+    }
+}
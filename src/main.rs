@@ -1,3 +1,8 @@
+mod inspect;
+mod memsim;
+mod slicing;
+mod tracker;
+
 // Ownership is Rust's most unique feature and has deep implications for the rest of the language. It enables Rust to make memory safety guarantees without needing a garbage collector, so it's important to understand how ownership works.
 // Implications: The action or state of being involved in something.
 // Ownership is a set of rules that govern how a Rust program manages memory, if any of the rules are violated, the program won't compile.
@@ -38,6 +43,21 @@
 // At this point, the relationship between scopes and when variables are valid is similar to that in other programming languages.
 
 fn main() {
+    // `memsim` turns the push/pop-vs-allocate/free comments above into something runnable: a stack push never searches, a heap allocation does.
+    let mut stack = memsim::StackSim::new();
+    stack.push(8);
+    stack.push(4);
+    println!("stack frames: {}", stack.len());
+    println!("out-of-order pop: {:?}", stack.pop(8)); // rejected, 4 is on top
+    stack.pop(4).expect("4 is the top frame");
+    stack.pop(8).expect("8 is now the top frame");
+    println!("stack empty: {}", stack.is_empty());
+
+    let mut heap = memsim::HeapSim::new(64);
+    let block = heap.allocate(16).expect("enough room in a 64-byte heap");
+    heap.free(block).expect("block was allocated above");
+    println!("heap searches performed: {}", heap.searches_performed());
+
     let mut s: &str = "wow"; // This variable refers to a string literal, where the value of the string is hardcoded into the text of our final executable. This is why string literals are fast and efficient.
     // If you don't mutate the variable while using mut it will result a compile-time warning because `#[warn(unused_mut)]` is on by default
     // If you assigned a value to the variable and didn't use it before mutating it there will be a compile-time warning because `#[warn(unused_assignments)]` is on by default
@@ -64,6 +84,11 @@ fn strings() {
     // When a variable goes out of scope, Rust calls a special function for us. This function is called drop, and it's where the author of String can put the code to return the memory. Rust calls drop automatically at the closing curly bracket.
     // In C++, this pattern of deallocating resources at the end of an item's lifetime is sometimes called Resource Acquisition Is Initialization (RAII). The drop function in Rust will be familiar to you if you\ve used RAII patterns.
 
+    // `tracker::run_scope` makes the paragraph above observable: it creates several values in nested blocks and records the order drop actually runs in.
+    for event in tracker::run_scope() {
+        println!("{event}");
+    }
+
 
 
     let s1 = String::from("hello");
@@ -73,6 +98,10 @@ fn strings() {
 
     // The length is how much memory, in bytes, the contents of the String are currently using. The capacity is the total amount of memory, in bytes, that the String has received from the allocator. When we assign s1 to s2, the String data is copied, meaning we copy the pointer, the length, and the capacity that are on the stack. We do not copy the data on the heap that the pointer refers to.
 
+    // `inspect::describe` pulls out that (pointer, length, capacity) triple so it can be checked instead of just asserted in prose.
+    let s1 = String::from("hello");
+    println!("{:?}", inspect::describe(&s1));
+    inspect::demo_growth();
 
 
     // Earlier, we said that when a variable goes out of scope, Rust automatically calls the drop function and cleans up the heap memory for that variable. But if the two data pointers are pointing to the same location. This will lead to a problem: when s2 and s1 go out of scope, they will both try to free the same memory. This is known as a double free error and is one of the memory safety bugs we mentioned previously. Freeing memory twice can lead to memory corruption, which can potentially lead to security vulnerabilities. To ensure memory safety, after the line let s2 = s1; Rust considers s1as no longer valid. Therefore, Rust doesn't need to free anything when s1 goes out of scope.
@@ -151,6 +180,13 @@ fn strings() {
 
     // String slice range indices must occur at valid UTF-8 character boundaries. If you attempt to create a string slice in the middle of a multibyte character, your program will exit with an error. Discussed more on page 147.
 
+    // `slicing::safe_slice` (and the `safe_prefix`/`safe_suffix` sugar) check the boundary first and return `None` instead of panicking.
+    let greeting = "caf\u{e9}";
+    println!("{:?}", slicing::safe_slice(greeting, 0, 3)); // Some("caf")
+    println!("{:?}", slicing::safe_slice(greeting, 0, 4)); // None: mid-character
+    println!("{:?}", slicing::safe_prefix(greeting, 3)); // Some("caf")
+    println!("{:?}", slicing::safe_suffix(greeting, 3)); // Some("\u{e9}")
+
     // You can't borrow a value as a mutable if it is declared as immutable.
 
 
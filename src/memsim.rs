@@ -0,0 +1,213 @@
+// The opening comments model the stack as push/pop and the heap as
+// allocate/free, and claim the heap costs more because the allocator has to
+// go looking for space. `StackSim` and `HeapSim` act that out: a `StackSim`
+// only ever touches its top frame, while `HeapSim` does a real first-fit
+// search (with coalescing on free) so the search count can be counted, not
+// just taken on faith.
+
+/// A fixed-size-frame stack. Frames must come off in the reverse of the
+/// order they went on, exactly like the plates-on-a-pile analogy.
+#[derive(Default)]
+pub struct StackSim {
+    frames: Vec<usize>,
+}
+
+/// Returned by `StackSim::pop` when the caller asks to remove a frame that
+/// isn't on top.
+#[derive(Debug, PartialEq, Eq)]
+pub struct OutOfOrderPop;
+
+impl StackSim {
+    pub fn new() -> Self {
+        StackSim::default()
+    }
+
+    pub fn push(&mut self, frame_size: usize) {
+        self.frames.push(frame_size);
+    }
+
+    /// Pops the top frame, rejecting the request if `expected_size` doesn't
+    /// match what's actually on top (i.e. the caller tried to remove
+    /// something other than the most recently pushed frame).
+    pub fn pop(&mut self, expected_size: usize) -> Result<usize, OutOfOrderPop> {
+        match self.frames.last() {
+            Some(&top) if top == expected_size => Ok(self.frames.pop().unwrap()),
+            _ => Err(OutOfOrderPop),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+/// An opaque handle to a heap allocation, returned by `HeapSim::allocate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Handle(usize);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HeapError {
+    OutOfMemory,
+    DoubleFree,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Block {
+    start: usize,
+    size: usize,
+    busy: bool,
+}
+
+/// A simulated heap: a flat byte range carved into busy/free blocks. Finding
+/// space for a new allocation means walking the free list (`searches`
+/// counts how many blocks that takes), unlike a stack push which is always
+/// "put it on top".
+pub struct HeapSim {
+    blocks: Vec<Block>,
+    searches: usize,
+}
+
+impl HeapSim {
+    pub fn new(capacity: usize) -> Self {
+        HeapSim {
+            blocks: vec![Block {
+                start: 0,
+                size: capacity,
+                busy: false,
+            }],
+            searches: 0,
+        }
+    }
+
+    /// How many free blocks have been inspected across all `allocate` calls
+    /// so far, i.e. the bookkeeping work a stack push never has to do.
+    pub fn searches_performed(&self) -> usize {
+        self.searches
+    }
+
+    /// First-fit: returns a handle to the first free block big enough to
+    /// hold `size`, splitting off the remainder if there's any left over.
+    pub fn allocate(&mut self, size: usize) -> Result<Handle, HeapError> {
+        for i in 0..self.blocks.len() {
+            self.searches += 1;
+            let block = self.blocks[i];
+            if !block.busy && block.size >= size {
+                if block.size > size {
+                    self.blocks.insert(
+                        i + 1,
+                        Block {
+                            start: block.start + size,
+                            size: block.size - size,
+                            busy: false,
+                        },
+                    );
+                }
+                self.blocks[i].size = size;
+                self.blocks[i].busy = true;
+                return Ok(Handle(self.blocks[i].start));
+            }
+        }
+        Err(HeapError::OutOfMemory)
+    }
+
+    /// Frees the block behind `handle`, then coalesces it with an
+    /// immediately adjacent free block on either side.
+    pub fn free(&mut self, handle: Handle) -> Result<(), HeapError> {
+        let i = self
+            .blocks
+            .iter()
+            .position(|b| b.start == handle.0 && b.busy)
+            .ok_or(HeapError::DoubleFree)?;
+
+        self.blocks[i].busy = false;
+
+        if i + 1 < self.blocks.len() && !self.blocks[i + 1].busy {
+            self.blocks[i].size += self.blocks[i + 1].size;
+            self.blocks.remove(i + 1);
+        }
+        if i > 0 && !self.blocks[i - 1].busy {
+            self.blocks[i - 1].size += self.blocks[i].size;
+            self.blocks.remove(i);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_rejects_out_of_order_pop() {
+        let mut stack = StackSim::new();
+        stack.push(8);
+        stack.push(4);
+
+        assert_eq!(stack.pop(8), Err(OutOfOrderPop));
+        assert_eq!(stack.pop(4), Ok(4));
+        assert_eq!(stack.pop(8), Ok(8));
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn heap_first_fit_and_fragmentation() {
+        let mut heap = HeapSim::new(90);
+
+        let a = heap.allocate(30).unwrap();
+        let _b = heap.allocate(30).unwrap();
+        let c = heap.allocate(30).unwrap();
+
+        // Freeing a and c leaves two separate free blocks with b in between:
+        // fragmentation means a later request bigger than either gap fails
+        // even though the total free space (70) would technically fit it.
+        heap.free(a).unwrap();
+        heap.free(c).unwrap();
+        assert_eq!(heap.allocate(40), Err(HeapError::OutOfMemory));
+
+        // But a request that fits in the first free gap succeeds.
+        assert!(heap.allocate(30).is_ok());
+    }
+
+    #[test]
+    fn heap_coalesces_adjacent_free_blocks() {
+        let mut heap = HeapSim::new(100);
+
+        let a = heap.allocate(30).unwrap();
+        let b = heap.allocate(30).unwrap();
+
+        heap.free(a).unwrap();
+        heap.free(b).unwrap();
+
+        // a and b were adjacent, so freeing both should merge back into one
+        // block big enough for something neither alone could hold.
+        assert!(heap.allocate(60).is_ok());
+    }
+
+    #[test]
+    fn heap_detects_double_free() {
+        let mut heap = HeapSim::new(100);
+        let a = heap.allocate(10).unwrap();
+
+        heap.free(a).unwrap();
+        assert_eq!(heap.free(a), Err(HeapError::DoubleFree));
+    }
+
+    #[test]
+    fn allocation_counts_searches_performed() {
+        let mut heap = HeapSim::new(100);
+        let a = heap.allocate(10).unwrap();
+        let _b = heap.allocate(10).unwrap();
+        heap.free(a).unwrap();
+
+        // Unlike a stack push, even finding the block `a` just freed costs
+        // the allocator an inspection; the counter should keep climbing.
+        let searches_before = heap.searches_performed();
+        heap.allocate(10).unwrap();
+        assert!(heap.searches_performed() > searches_before);
+    }
+}
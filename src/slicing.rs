@@ -0,0 +1,66 @@
+// `strings()` warns that slicing a string outside a UTF-8 character boundary
+// panics. These helpers check `is_char_boundary` up front and hand back
+// `None` on a bad split, so the caller gets a value to match on rather than
+// a program that exits with an error.
+
+/// Returns `&s[start..end]` if both indices land on a char boundary, else `None`.
+pub fn safe_slice(s: &str, start: usize, end: usize) -> Option<&str> {
+    if start > end || end > s.len() || !s.is_char_boundary(start) || !s.is_char_boundary(end) {
+        return None;
+    }
+    Some(&s[start..end])
+}
+
+/// Mirrors the `&s[..len]` sugar: the first `len` bytes, if `len` is a char boundary.
+pub fn safe_prefix(s: &str, len: usize) -> Option<&str> {
+    safe_slice(s, 0, len)
+}
+
+/// Mirrors the `&s[start..]` sugar: everything from `start` onward, if it's a char boundary.
+pub fn safe_suffix(s: &str, start: usize) -> Option<&str> {
+    safe_slice(s, start, s.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_slices_at_any_boundary() {
+        let s = "hello";
+        assert_eq!(safe_slice(s, 0, 2), Some("he"));
+        assert_eq!(safe_prefix(s, 2), Some("he"));
+        assert_eq!(safe_suffix(s, 3), Some("lo"));
+    }
+
+    #[test]
+    fn multibyte_char_boundaries_are_respected() {
+        let s = "caf\u{e9}"; // "café": the 'é' is a 2-byte UTF-8 sequence.
+        assert_eq!(s.len(), 5);
+
+        // Slicing right up to the start of 'é' (byte 3) is fine.
+        assert_eq!(safe_slice(s, 0, 3), Some("caf"));
+        // Slicing through the middle of 'é' (byte 4) is not a char boundary.
+        assert_eq!(safe_slice(s, 0, 4), None);
+        // The full string is a valid slice.
+        assert_eq!(safe_slice(s, 0, 5), Some(s));
+    }
+
+    #[test]
+    fn japanese_multibyte_input() {
+        let s = "\u{65e5}\u{672c}"; // "日本": each character is 3 bytes.
+        assert_eq!(s.len(), 6);
+
+        assert_eq!(safe_prefix(s, 3), Some("\u{65e5}"));
+        assert_eq!(safe_prefix(s, 1), None); // mid-character
+        assert_eq!(safe_suffix(s, 3), Some("\u{672c}"));
+        assert_eq!(safe_suffix(s, 2), None); // mid-character
+    }
+
+    #[test]
+    fn out_of_range_indices_yield_none() {
+        let s = "hi";
+        assert_eq!(safe_slice(s, 0, 10), None);
+        assert_eq!(safe_slice(s, 2, 1), None);
+    }
+}
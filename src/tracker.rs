@@ -0,0 +1,93 @@
+// The prose in `strings()` says Rust calls `drop` automatically at the
+// closing curly brace. `Tracked` logs each drop as it happens so that claim
+// can be checked: values release in the reverse of the order they were
+// declared, and `drop(x)` pulls a release forward to wherever it's called.
+
+use std::cell::RefCell;
+
+/// A value whose only job is to record when it gets dropped.
+///
+/// Each `Tracked` pushes a `("drop", name)` event into the shared log the
+/// moment its `Drop::drop` runs, so the order values go out of scope can be
+/// observed instead of just described in comments.
+pub struct Tracked<'a> {
+    name: String,
+    log: &'a RefCell<Vec<String>>,
+}
+
+impl<'a> Tracked<'a> {
+    pub fn new(name: &str, log: &'a RefCell<Vec<String>>) -> Self {
+        log.borrow_mut().push(format!("create {name}"));
+        Tracked {
+            name: name.to_string(),
+            log,
+        }
+    }
+}
+
+impl<'a> Drop for Tracked<'a> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(format!("drop {}", self.name));
+    }
+}
+
+/// Creates a handful of `Tracked` values in nested blocks and returns the
+/// full creation/drop log, in the order the events actually happened.
+pub fn run_scope() -> Vec<String> {
+    let log = RefCell::new(Vec::new());
+
+    let a = Tracked::new("a", &log);
+    {
+        let _b = Tracked::new("b", &log);
+        let c = Tracked::new("c", &log);
+        drop(c); // explicit drop: "drop c" happens here, not at the block's end.
+        let _d = Tracked::new("d", &log);
+    }
+    let e = Tracked::new("e", &log);
+    drop(e);
+    drop(a);
+
+    log.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_happen_in_lifo_order_within_a_scope() {
+        let events = run_scope();
+
+        // Nothing is dropped until a value's block ends.
+        assert_eq!(events[0], "create a");
+        assert_eq!(events[1], "create b");
+        assert_eq!(events[2], "create c");
+
+        // The explicit drop(c) fires before the inner block closes, ahead of d.
+        assert_eq!(events[3], "drop c");
+        assert_eq!(events[4], "create d");
+
+        // Inner block closes: d then b go, last in first out.
+        assert_eq!(events[5], "drop d");
+        assert_eq!(events[6], "drop b");
+
+        assert_eq!(events[7], "create e");
+
+        // main's remaining values drop in reverse declaration order: e, a.
+        assert_eq!(events[8], "drop e");
+        assert_eq!(events[9], "drop a");
+    }
+
+    #[test]
+    fn explicit_drop_moves_the_event_earlier() {
+        let log = RefCell::new(Vec::new());
+
+        let first = Tracked::new("first", &log);
+        let second = Tracked::new("second", &log);
+        drop(first);
+        drop(second);
+
+        let events = log.into_inner();
+        assert_eq!(events, vec!["create first", "create second", "drop first", "drop second"]);
+    }
+}
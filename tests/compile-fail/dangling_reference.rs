@@ -0,0 +1,9 @@
+// Rust guarantees references never outlive the data they point to.
+fn dangle() -> &String {
+    let s = String::from("hello");
+    &s
+}
+
+fn main() {
+    let _ = dangle();
+}
@@ -0,0 +1,8 @@
+// You cannot have a mutable reference while an immutable one is still live.
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &s;
+    let r2 = &s;
+    let r3 = &mut s;
+    println!("{r1}, {r2}, and {r3}");
+}
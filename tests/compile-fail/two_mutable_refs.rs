@@ -0,0 +1,7 @@
+// You can have only one mutable reference to a value at a time.
+fn main() {
+    let mut s = String::from("hello");
+    let r1 = &mut s;
+    let r2 = &mut s;
+    println!("{r1}, {r2}");
+}
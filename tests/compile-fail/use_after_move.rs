@@ -0,0 +1,7 @@
+// `let s2 = s1;` moves the String; s1 is no longer valid afterward.
+fn main() {
+    let s1 = String::from("hello");
+    let s2 = s1;
+    println!("{s1}, world!");
+    let _ = s2;
+}
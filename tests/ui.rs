@@ -0,0 +1,10 @@
+// Pins down the borrow-checker rules that `strings()` only describes in
+// commented-out snippets (use-after-move, aliasing a `&mut`, a dangling
+// reference). Each case under `tests/compile-fail` is expected to fail to
+// compile with the paired `.stderr`.
+
+#[test]
+fn borrow_checker_rules() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/*.rs");
+}